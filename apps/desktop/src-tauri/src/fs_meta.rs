@@ -0,0 +1,152 @@
+// Filesystem metadata for attaching files to memories and browsing them in-app.
+use std::fs;
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct EntryMetaData {
+    pub name: String,
+    pub path: String,
+    pub size: u64,
+    pub is_directory: bool,
+    pub is_file: bool,
+    pub is_symlink: bool,
+    pub child_count: Option<usize>,
+    pub permissions: String,
+    pub created: Option<u64>,
+    pub modified: Option<u64>,
+    pub accessed: Option<u64>,
+}
+
+fn millis_since_epoch(time: std::io::Result<std::time::SystemTime>) -> Option<u64> {
+    time.ok()
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_millis() as u64)
+}
+
+#[cfg(unix)]
+fn permission_string(metadata: &fs::Metadata) -> String {
+    use std::os::unix::fs::PermissionsExt;
+    let mode = metadata.permissions().mode();
+    let bit = |shift: u32, c: char| if mode & (1 << shift) != 0 { c } else { '-' };
+    format!(
+        "{}{}{}{}{}{}{}{}{}",
+        bit(8, 'r'),
+        bit(7, 'w'),
+        bit(6, 'x'),
+        bit(5, 'r'),
+        bit(4, 'w'),
+        bit(3, 'x'),
+        bit(2, 'r'),
+        bit(1, 'w'),
+        bit(0, 'x'),
+    )
+}
+
+#[cfg(not(unix))]
+fn permission_string(metadata: &fs::Metadata) -> String {
+    if metadata.permissions().readonly() {
+        "r--r--r--".to_string()
+    } else {
+        "rw-rw-rw-".to_string()
+    }
+}
+
+fn entry_metadata(path: &Path) -> Result<EntryMetaData, String> {
+    let metadata = fs::symlink_metadata(path).map_err(|e| e.to_string())?;
+    let is_symlink = metadata.file_type().is_symlink();
+    // Follow the symlink for size/type/timestamps when possible, matching how
+    // callers expect `is_directory`/`is_file` to describe the link's target.
+    let resolved = if is_symlink {
+        fs::metadata(path).unwrap_or(metadata)
+    } else {
+        metadata
+    };
+
+    let child_count = if resolved.is_dir() {
+        fs::read_dir(path).ok().map(|entries| entries.count())
+    } else {
+        None
+    };
+
+    Ok(EntryMetaData {
+        name: path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        path: path.to_string_lossy().to_string(),
+        size: resolved.len(),
+        is_directory: resolved.is_dir(),
+        is_file: resolved.is_file(),
+        is_symlink,
+        child_count,
+        permissions: permission_string(&resolved),
+        created: millis_since_epoch(resolved.created()),
+        modified: millis_since_epoch(resolved.modified()),
+        accessed: millis_since_epoch(resolved.accessed()),
+    })
+}
+
+#[tauri::command]
+pub fn get_entry_metadata(path: String) -> Result<EntryMetaData, String> {
+    entry_metadata(Path::new(&path))
+}
+
+#[tauri::command]
+pub fn list_dir(path: String) -> Result<Vec<EntryMetaData>, String> {
+    let entries = fs::read_dir(&path).map_err(|e| e.to_string())?;
+    entries
+        .map(|entry| entry.map_err(|e| e.to_string()).and_then(|e| entry_metadata(&e.path())))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    #[test]
+    fn permission_string_renders_rwx_bits() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = std::env::temp_dir().join("unimem-fs-meta-test-perm.txt");
+        fs::write(&path, "x").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o640)).unwrap();
+
+        let metadata = fs::metadata(&path).unwrap();
+        assert_eq!(permission_string(&metadata), "rw-r-----");
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn entry_metadata_reports_file_vs_directory() {
+        let dir = std::env::temp_dir().join("unimem-fs-meta-test-dir");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let file_path = dir.join("child.txt");
+        fs::write(&file_path, "hello").unwrap();
+
+        let dir_meta = entry_metadata(&dir).unwrap();
+        assert!(dir_meta.is_directory);
+        assert!(!dir_meta.is_file);
+        assert_eq!(dir_meta.child_count, Some(1));
+
+        let file_meta = entry_metadata(&file_path).unwrap();
+        assert!(file_meta.is_file);
+        assert!(!file_meta.is_directory);
+        assert_eq!(file_meta.size, 5);
+        assert_eq!(file_meta.child_count, None);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn entry_metadata_errors_on_missing_path() {
+        let path = std::env::temp_dir().join("unimem-fs-meta-test-missing-does-not-exist");
+        let _ = fs::remove_file(&path);
+        assert!(entry_metadata(&path).is_err());
+    }
+}
@@ -0,0 +1,209 @@
+// Global hotkey handling for the quick-capture window.
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, WebviewUrl, WebviewWindowBuilder};
+use tauri_plugin_global_shortcut::GlobalShortcutExt;
+
+use crate::notes::{NoteId, Store};
+use crate::search;
+
+const CAPTURE_WINDOW_LABEL: &str = "capture";
+const DEFAULT_ACCELERATOR: &str = "Ctrl+Shift+Q";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct HotkeyConfig {
+    accelerator: String,
+}
+
+pub struct HotkeyState {
+    config_path: PathBuf,
+    current: Mutex<Option<String>>,
+}
+
+impl HotkeyState {
+    fn new(app_data_dir: &Path) -> Self {
+        Self {
+            config_path: app_data_dir.join("hotkey.json"),
+            current: Mutex::new(None),
+        }
+    }
+
+    fn load_accelerator(&self) -> String {
+        fs::read_to_string(&self.config_path)
+            .ok()
+            .and_then(|s| serde_json::from_str::<HotkeyConfig>(&s).ok())
+            .map(|c| c.accelerator)
+            .unwrap_or_else(|| DEFAULT_ACCELERATOR.to_string())
+    }
+
+    fn persist_accelerator(&self, accelerator: &str) -> Result<(), String> {
+        if let Some(parent) = self.config_path.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        let json = serde_json::to_string_pretty(&HotkeyConfig {
+            accelerator: accelerator.to_string(),
+        })
+        .map_err(|e| e.to_string())?;
+        fs::write(&self.config_path, json).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod state_tests {
+    use super::*;
+
+    fn scratch_state(name: &str) -> HotkeyState {
+        let dir = std::env::temp_dir().join(format!("unimem-hotkey-test-{name}"));
+        let _ = fs::remove_dir_all(&dir);
+        HotkeyState::new(&dir)
+    }
+
+    #[test]
+    fn load_accelerator_defaults_when_no_config_file() {
+        let state = scratch_state("default");
+        assert_eq!(state.load_accelerator(), DEFAULT_ACCELERATOR);
+    }
+
+    #[test]
+    fn persist_then_load_round_trips_accelerator() {
+        let state = scratch_state("round-trip");
+        state.persist_accelerator("Ctrl+Alt+N").unwrap();
+        assert_eq!(state.load_accelerator(), "Ctrl+Alt+N");
+    }
+
+    #[test]
+    fn load_accelerator_falls_back_on_corrupt_config_file() {
+        let state = scratch_state("corrupt");
+        fs::create_dir_all(state.config_path.parent().unwrap()).unwrap();
+        fs::write(&state.config_path, "not json").unwrap();
+        assert_eq!(state.load_accelerator(), DEFAULT_ACCELERATOR);
+    }
+}
+
+fn ensure_capture_window(app: &AppHandle) -> Result<(), String> {
+    if app.get_webview_window(CAPTURE_WINDOW_LABEL).is_some() {
+        return Ok(());
+    }
+    WebviewWindowBuilder::new(app, CAPTURE_WINDOW_LABEL, WebviewUrl::App("capture.html".into()))
+        .title("Quick capture")
+        .inner_size(480.0, 120.0)
+        .always_on_top(true)
+        .decorations(false)
+        .visible(false)
+        .build()
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+pub(crate) fn toggle_capture_window(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window(CAPTURE_WINDOW_LABEL) {
+        let visible = window.is_visible().unwrap_or(false);
+        if visible {
+            hide_capture_window(app);
+        } else {
+            let _ = window.show();
+            let _ = window.set_focus();
+        }
+    }
+}
+
+fn hide_capture_window(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window(CAPTURE_WINDOW_LABEL) {
+        let _ = window.hide();
+    }
+}
+
+/// Dismisses the capture window. The capture window's frontend calls this on
+/// an Escape `keydown`, so the key is only intercepted while that window has
+/// focus rather than stealing Escape system-wide like a global shortcut would.
+#[tauri::command]
+pub fn dismiss_capture(app: AppHandle) {
+    hide_capture_window(&app);
+}
+
+fn bind_accelerator(app: &AppHandle, accelerator: &str) -> Result<(), String> {
+    app.global_shortcut()
+        .on_shortcut(accelerator, move |app, _shortcut, _event| {
+            toggle_capture_window(app);
+        })
+        .map_err(|e| e.to_string())
+}
+
+fn unbind_accelerator(app: &AppHandle, accelerator: &str) -> Result<(), String> {
+    app.global_shortcut()
+        .unregister(accelerator)
+        .map_err(|e| e.to_string())
+}
+
+/// Creates the hidden capture window and binds the persisted (or default) accelerator.
+/// Called once from `.setup()`.
+pub fn init(app: &AppHandle, app_data_dir: &Path) -> Result<(), String> {
+    ensure_capture_window(app)?;
+
+    let state = HotkeyState::new(app_data_dir);
+    let accelerator = state.load_accelerator();
+    bind_accelerator(app, &accelerator)?;
+    *state.current.lock().map_err(|e| e.to_string())? = Some(accelerator);
+    app.manage(state);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn register_capture_hotkey(
+    app: AppHandle,
+    state: tauri::State<HotkeyState>,
+    accelerator: String,
+) -> Result<(), String> {
+    let mut current = state.current.lock().map_err(|e| e.to_string())?;
+
+    // Bind the new accelerator before tearing down the old one: if the new
+    // chord is invalid or conflicts with another app, this fails and bails
+    // out with the old accelerator left in place rather than stranding the
+    // user with neither bound.
+    bind_accelerator(&app, &accelerator)?;
+    state.persist_accelerator(&accelerator)?;
+
+    if let Some(existing) = current.replace(accelerator.clone()) {
+        if existing != accelerator {
+            let _ = unbind_accelerator(&app, &existing);
+        }
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn unregister_capture_hotkey(
+    app: AppHandle,
+    state: tauri::State<HotkeyState>,
+) -> Result<(), String> {
+    let mut current = state.current.lock().map_err(|e| e.to_string())?;
+    if let Some(existing) = current.take() {
+        unbind_accelerator(&app, &existing)?;
+    }
+    Ok(())
+}
+
+/// Called by the capture window when the user submits their line of text:
+/// appends it straight into the note store/search index, then dismisses the
+/// window, completing the single-line note-capture workflow.
+#[tauri::command]
+pub fn submit_capture(
+    app: AppHandle,
+    notes_state: tauri::State<Mutex<Store>>,
+    index_state: tauri::State<Mutex<search::Index>>,
+    content: String,
+) -> Result<NoteId, String> {
+    let id = notes_state
+        .lock()
+        .map_err(|e| e.to_string())?
+        .create(content.clone(), Vec::new())?;
+    index_state
+        .lock()
+        .map_err(|e| e.to_string())?
+        .index_note(id, &content)?;
+    hide_capture_window(&app);
+    Ok(id)
+}
@@ -1,7 +1,21 @@
 // Prevents additional console window on Windows in release
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-use tauri::Manager;
+mod fs_meta;
+mod hotkey;
+mod notes;
+mod search;
+mod tray;
+mod updater;
+
+use tauri::{Manager, WindowEvent};
+
+use fs_meta::{get_entry_metadata, list_dir};
+use hotkey::{dismiss_capture, register_capture_hotkey, submit_capture, unregister_capture_hotkey};
+use notes::{create_note, delete_note, get_note, list_notes, update_note};
+use search::search_notes;
+use tray::set_run_in_background;
+use updater::{check_for_update, install_update, PendingUpdate};
 
 // Custom commands that can be called from the frontend
 #[tauri::command]
@@ -25,17 +39,50 @@ fn main() {
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_dialog::init())
+        .plugin(tauri_plugin_global_shortcut::Builder::new().build())
+        .plugin(tauri_plugin_updater::Builder::new().build())
+        .plugin(tauri_plugin_process::init())
         .invoke_handler(tauri::generate_handler![
             get_app_data_dir,
             get_document_dir,
+            create_note,
+            list_notes,
+            get_note,
+            update_note,
+            delete_note,
+            register_capture_hotkey,
+            unregister_capture_hotkey,
+            submit_capture,
+            dismiss_capture,
+            set_run_in_background,
+            get_entry_metadata,
+            list_dir,
+            search_notes,
+            check_for_update,
+            install_update,
         ])
         .setup(|app| {
-            // Log app directories on startup
-            if let Ok(app_data) = app.path().app_data_dir() {
-                println!("App data directory: {:?}", app_data);
-            }
+            // Every managed command below extracts notes/search/hotkey state, so a
+            // missing app data dir must abort startup rather than leave that state
+            // unmanaged (Tauri's `State` extractor panics on first use otherwise).
+            let app_data = app.path().app_data_dir().map_err(|e| e.to_string())?;
+            println!("App data directory: {:?}", app_data);
+
+            let store = notes::init_store(&app_data);
+            let existing_notes = store.lock().map_err(|e| e.to_string())?.list(None);
+            app.manage(search::init_index(&app_data, &existing_notes));
+            app.manage(store);
+            hotkey::init(app.handle(), &app_data)?;
+
+            tray::init(app.handle())?;
+            app.manage(PendingUpdate::default());
             Ok(())
         })
+        .on_window_event(|window, event| {
+            if let WindowEvent::CloseRequested { api, .. } = event {
+                tray::handle_close_requested(window.app_handle(), window, api);
+            }
+        })
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
@@ -0,0 +1,250 @@
+// Note storage: a small JSON-backed store for the notes/memory CRUD surface.
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use tauri::Manager;
+
+pub type NoteId = u64;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Note {
+    pub id: NoteId,
+    pub content: String,
+    pub tags: Vec<String>,
+    pub created: u64,
+    pub modified: u64,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct StoreFile {
+    next_id: NoteId,
+    notes: Vec<Note>,
+}
+
+pub struct Store {
+    path: PathBuf,
+    file: StoreFile,
+}
+
+impl Store {
+    fn load(path: PathBuf) -> Self {
+        let file = fs::read_to_string(&path)
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+        Self { path, file }
+    }
+
+    fn persist(&self) -> Result<(), String> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        let json = serde_json::to_string_pretty(&self.file).map_err(|e| e.to_string())?;
+        fs::write(&self.path, json).map_err(|e| e.to_string())
+    }
+
+    pub(crate) fn create(&mut self, content: String, tags: Vec<String>) -> Result<NoteId, String> {
+        let id = self.file.next_id;
+        self.file.next_id += 1;
+        let now = now_millis();
+        self.file.notes.push(Note {
+            id,
+            content,
+            tags,
+            created: now,
+            modified: now,
+        });
+        self.persist()?;
+        Ok(id)
+    }
+
+    pub(crate) fn list(&self, filter: Option<String>) -> Vec<Note> {
+        match filter {
+            Some(f) => {
+                let needle = f.to_lowercase();
+                self.file
+                    .notes
+                    .iter()
+                    .filter(|n| {
+                        n.content.to_lowercase().contains(&needle)
+                            || n.tags.iter().any(|t| t.to_lowercase().contains(&needle))
+                    })
+                    .cloned()
+                    .collect()
+            }
+            None => self.file.notes.clone(),
+        }
+    }
+
+    fn get(&self, id: NoteId) -> Option<Note> {
+        self.file.notes.iter().find(|n| n.id == id).cloned()
+    }
+
+    fn update(&mut self, id: NoteId, content: String) -> Result<Note, String> {
+        let note = self
+            .file
+            .notes
+            .iter_mut()
+            .find(|n| n.id == id)
+            .ok_or_else(|| format!("note {id} not found"))?;
+        note.content = content;
+        note.modified = now_millis();
+        let updated = note.clone();
+        self.persist()?;
+        Ok(updated)
+    }
+
+    fn delete(&mut self, id: NoteId) -> Result<(), String> {
+        let before = self.file.notes.len();
+        self.file.notes.retain(|n| n.id != id);
+        if self.file.notes.len() == before {
+            return Err(format!("note {id} not found"));
+        }
+        self.persist()
+    }
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+fn notes_path(app_data_dir: &Path) -> PathBuf {
+    app_data_dir.join("notes.json")
+}
+
+/// Builds the store from the app data dir, called once from `.setup()`.
+pub fn init_store(app_data_dir: &Path) -> Mutex<Store> {
+    Mutex::new(Store::load(notes_path(app_data_dir)))
+}
+
+#[tauri::command]
+pub fn create_note(
+    state: tauri::State<Mutex<Store>>,
+    index_state: tauri::State<Mutex<crate::search::Index>>,
+    content: String,
+    tags: Vec<String>,
+) -> Result<NoteId, String> {
+    let id = state.lock().map_err(|e| e.to_string())?.create(content.clone(), tags)?;
+    index_state
+        .lock()
+        .map_err(|e| e.to_string())?
+        .index_note(id, &content)?;
+    Ok(id)
+}
+
+#[tauri::command]
+pub fn list_notes(
+    state: tauri::State<Mutex<Store>>,
+    filter: Option<String>,
+) -> Result<Vec<Note>, String> {
+    Ok(state.lock().map_err(|e| e.to_string())?.list(filter))
+}
+
+#[tauri::command]
+pub fn get_note(state: tauri::State<Mutex<Store>>, id: NoteId) -> Result<Note, String> {
+    state
+        .lock()
+        .map_err(|e| e.to_string())?
+        .get(id)
+        .ok_or_else(|| format!("note {id} not found"))
+}
+
+#[tauri::command]
+pub fn update_note(
+    state: tauri::State<Mutex<Store>>,
+    index_state: tauri::State<Mutex<crate::search::Index>>,
+    id: NoteId,
+    content: String,
+) -> Result<Note, String> {
+    let updated = state.lock().map_err(|e| e.to_string())?.update(id, content)?;
+    index_state
+        .lock()
+        .map_err(|e| e.to_string())?
+        .index_note(id, &updated.content)?;
+    Ok(updated)
+}
+
+#[tauri::command]
+pub fn delete_note(
+    state: tauri::State<Mutex<Store>>,
+    index_state: tauri::State<Mutex<crate::search::Index>>,
+    id: NoteId,
+) -> Result<(), String> {
+    state.lock().map_err(|e| e.to_string())?.delete(id)?;
+    index_state.lock().map_err(|e| e.to_string())?.remove_note(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch_store(name: &str) -> Store {
+        let path = std::env::temp_dir().join(format!("unimem-notes-test-{name}.json"));
+        let _ = fs::remove_file(&path);
+        Store::load(path)
+    }
+
+    #[test]
+    fn create_assigns_monotonic_ids() {
+        let mut store = scratch_store("monotonic-ids");
+        let first = store.create("one".into(), vec![]).unwrap();
+        let second = store.create("two".into(), vec![]).unwrap();
+        assert!(second > first);
+    }
+
+    #[test]
+    fn get_returns_none_for_unknown_id() {
+        let store = scratch_store("get-unknown");
+        assert!(store.get(999).is_none());
+    }
+
+    #[test]
+    fn update_changes_content_and_modified_but_not_created() {
+        let mut store = scratch_store("update");
+        let id = store.create("original".into(), vec![]).unwrap();
+        let before = store.get(id).unwrap();
+        // Timestamps are in millis; wait so `modified` can move past `created`.
+        std::thread::sleep(std::time::Duration::from_millis(5));
+
+        let updated = store.update(id, "changed".into()).unwrap();
+        assert_eq!(updated.content, "changed");
+        assert!(updated.modified > before.modified);
+        assert_eq!(updated.created, before.created);
+    }
+
+    #[test]
+    fn update_unknown_id_returns_err() {
+        let mut store = scratch_store("update-unknown");
+        assert!(store.update(999, "x".into()).is_err());
+    }
+
+    #[test]
+    fn delete_removes_note_and_is_idempotent_failure() {
+        let mut store = scratch_store("delete");
+        let id = store.create("to remove".into(), vec![]).unwrap();
+        store.delete(id).unwrap();
+        assert!(store.get(id).is_none());
+        assert!(store.delete(id).is_err());
+    }
+
+    #[test]
+    fn list_filters_by_content_and_tags_case_insensitively() {
+        let mut store = scratch_store("list-filter");
+        store.create("Morning routine".into(), vec!["habit".into()]).unwrap();
+        store.create("Grocery list".into(), vec!["errand".into()]).unwrap();
+
+        let by_content = store.list(Some("morning".into()));
+        assert_eq!(by_content.len(), 1);
+
+        let by_tag = store.list(Some("ERRAND".into()));
+        assert_eq!(by_tag.len(), 1);
+
+        assert_eq!(store.list(None).len(), 2);
+    }
+}
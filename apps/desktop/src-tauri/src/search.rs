@@ -0,0 +1,292 @@
+// Inverted-index full-text search over stored notes, ranked by TF-IDF.
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+use crate::notes::{Note, NoteId};
+
+const STOPWORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "if", "in", "into", "is",
+    "it", "no", "not", "of", "on", "or", "such", "that", "the", "their", "then", "there",
+    "these", "they", "this", "to", "was", "will", "with",
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchHit {
+    pub id: NoteId,
+    pub score: f64,
+    pub snippet: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct IndexFile {
+    // token -> note ids containing it
+    postings: HashMap<String, Vec<NoteId>>,
+    // token -> note id -> term frequency within that note
+    term_freq: HashMap<String, HashMap<NoteId, usize>>,
+    // note ids that have been indexed, regardless of whether they tokenized to
+    // anything; drives `doc_count` so it can't drift from membership in
+    // `term_freq` (which an empty/stopwords-only note would never appear in).
+    indexed_ids: HashSet<NoteId>,
+}
+
+pub struct Index {
+    path: PathBuf,
+    file: IndexFile,
+}
+
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_lowercase())
+        .filter(|s| !STOPWORDS.contains(&s.as_str()))
+        .collect()
+}
+
+fn index_path(app_data_dir: &Path) -> PathBuf {
+    app_data_dir.join("search_index.json")
+}
+
+impl Index {
+    fn load(path: PathBuf) -> Self {
+        let file = fs::read_to_string(&path)
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+        Self { path, file }
+    }
+
+    fn persist(&self) -> Result<(), String> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        let json = serde_json::to_string_pretty(&self.file).map_err(|e| e.to_string())?;
+        fs::write(&self.path, json).map_err(|e| e.to_string())
+    }
+
+    /// Removes any existing postings for `id`, then re-tokenizes and re-indexes
+    /// `content`. Called on both note create and update.
+    pub fn index_note(&mut self, id: NoteId, content: &str) -> Result<(), String> {
+        self.remove_note(id)?;
+
+        let tokens = tokenize(content);
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for token in tokens {
+            *counts.entry(token).or_insert(0) += 1;
+        }
+        for (token, count) in counts {
+            self.file.postings.entry(token.clone()).or_default().push(id);
+            self.file
+                .term_freq
+                .entry(token)
+                .or_default()
+                .insert(id, count);
+        }
+        self.file.indexed_ids.insert(id);
+        self.persist()
+    }
+
+    pub fn remove_note(&mut self, id: NoteId) -> Result<(), String> {
+        for counts in self.file.term_freq.values_mut() {
+            counts.remove(&id);
+        }
+        for ids in self.file.postings.values_mut() {
+            ids.retain(|&existing| existing != id);
+        }
+        self.file.indexed_ids.remove(&id);
+        self.persist()
+    }
+
+    fn search(&self, query: &str, limit: usize, notes: &[Note]) -> Vec<SearchHit> {
+        let doc_count = self.file.indexed_ids.len().max(1) as f64;
+        let mut scores: HashMap<NoteId, f64> = HashMap::new();
+
+        for token in tokenize(query) {
+            let Some(ids) = self.file.postings.get(&token) else {
+                continue;
+            };
+            let df = ids.iter().collect::<std::collections::HashSet<_>>().len().max(1) as f64;
+            let idf = (doc_count / df).ln().max(0.0);
+            if let Some(freqs) = self.file.term_freq.get(&token) {
+                for (&id, &tf) in freqs {
+                    *scores.entry(id).or_insert(0.0) += tf as f64 * idf;
+                }
+            }
+        }
+
+        let mut hits: Vec<SearchHit> = scores
+            .into_iter()
+            .filter_map(|(id, score)| {
+                notes.iter().find(|n| n.id == id).map(|note| SearchHit {
+                    id,
+                    score,
+                    snippet: snippet_for(&note.content, query),
+                })
+            })
+            .collect();
+
+        hits.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+        hits.truncate(limit);
+        hits
+    }
+}
+
+/// Finds the char index of the first occurrence of `needle` within `chars`,
+/// comparing case-insensitively one char at a time so callers never have to
+/// reconcile byte offsets between a string and its lowercased form (whose
+/// byte length can differ for non-ASCII input).
+fn find_char_index(chars: &[char], needle: &str) -> Option<usize> {
+    let needle: Vec<char> = needle.chars().collect();
+    if needle.is_empty() || needle.len() > chars.len() {
+        return None;
+    }
+    chars.windows(needle.len()).position(|window| {
+        window
+            .iter()
+            .zip(needle.iter())
+            .all(|(&a, &b)| a.to_lowercase().eq(b.to_lowercase()))
+    })
+}
+
+fn snippet_for(content: &str, query: &str) -> String {
+    let chars: Vec<char> = content.chars().collect();
+    let query_tokens = tokenize(query);
+    let first_match = query_tokens
+        .iter()
+        .filter_map(|t| find_char_index(&chars, t))
+        .min();
+
+    const RADIUS: usize = 40;
+    match first_match {
+        Some(char_pos) => {
+            let start = char_pos.saturating_sub(RADIUS);
+            let end = (char_pos + RADIUS).min(chars.len());
+            chars[start..end].iter().collect::<String>().trim().to_string()
+        }
+        None => chars.into_iter().take(RADIUS * 2).collect(),
+    }
+}
+
+/// Loads the on-disk index, or builds it from scratch over `notes` if missing.
+pub fn init_index(app_data_dir: &Path, notes: &[Note]) -> Mutex<Index> {
+    let path = index_path(app_data_dir);
+    if path.exists() {
+        return Mutex::new(Index::load(path));
+    }
+    let mut index = Index::load(path);
+    for note in notes {
+        let _ = index.index_note(note.id, &note.content);
+    }
+    Mutex::new(index)
+}
+
+#[tauri::command]
+pub fn search_notes(
+    index_state: tauri::State<Mutex<Index>>,
+    notes_state: tauri::State<Mutex<crate::notes::Store>>,
+    query: String,
+    limit: usize,
+) -> Result<Vec<SearchHit>, String> {
+    let index = index_state.lock().map_err(|e| e.to_string())?;
+    let notes = notes_state.lock().map_err(|e| e.to_string())?.list(None);
+    Ok(index.search(&query, limit, &notes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch_index(name: &str) -> Index {
+        let path = std::env::temp_dir().join(format!("unimem-search-test-{name}.json"));
+        let _ = fs::remove_file(&path);
+        Index::load(path)
+    }
+
+    fn note(id: NoteId, content: &str) -> Note {
+        Note {
+            id,
+            content: content.to_string(),
+            tags: Vec::new(),
+            created: 0,
+            modified: 0,
+        }
+    }
+
+    #[test]
+    fn tokenize_lowercases_splits_and_drops_stopwords() {
+        assert_eq!(
+            tokenize("The quick-brown FOX, jumps!"),
+            vec!["quick", "brown", "fox", "jumps"]
+        );
+    }
+
+    #[test]
+    fn tokenize_handles_empty_and_stopword_only_input() {
+        assert!(tokenize("").is_empty());
+        assert!(tokenize("the and of").is_empty());
+    }
+
+    #[test]
+    fn index_note_then_remove_leaves_no_postings() {
+        let mut index = scratch_index("remove");
+        index.index_note(1, "alpha beta").unwrap();
+        assert!(index.file.postings.contains_key("alpha"));
+
+        index.remove_note(1).unwrap();
+        assert!(!index.file.postings.get("alpha").map(|ids| !ids.is_empty()).unwrap_or(false));
+        assert!(!index.file.indexed_ids.contains(&1));
+    }
+
+    #[test]
+    fn doc_count_tracks_notes_even_when_content_has_no_tokens() {
+        let mut index = scratch_index("doc-count");
+        index.index_note(1, "   ").unwrap(); // tokenizes to nothing
+        assert_eq!(index.file.indexed_ids.len(), 1);
+
+        index.remove_note(1).unwrap();
+        assert_eq!(index.file.indexed_ids.len(), 0);
+    }
+
+    #[test]
+    fn search_ranks_more_relevant_note_first() {
+        let mut index = scratch_index("rank");
+        index.index_note(1, "rust rust rust").unwrap();
+        index.index_note(2, "rust is nice").unwrap();
+        // A note without the term keeps idf = ln(N / df) above zero.
+        index.index_note(3, "hello world").unwrap();
+        let notes = vec![
+            note(1, "rust rust rust"),
+            note(2, "rust is nice"),
+            note(3, "hello world"),
+        ];
+
+        let hits = index.search("rust", 10, &notes);
+        assert_eq!(hits.first().map(|h| h.id), Some(1));
+    }
+
+    #[test]
+    fn search_with_no_matches_returns_empty() {
+        let mut index = scratch_index("no-match");
+        index.index_note(1, "hello world").unwrap();
+        let notes = vec![note(1, "hello world")];
+
+        assert!(index.search("zzz", 10, &notes).is_empty());
+    }
+
+    #[test]
+    fn snippet_for_does_not_panic_on_multibyte_content() {
+        let content = "héllo café 日本語 emoji 🎉 match world";
+        assert_eq!(snippet_for(content, "match"), snippet_for(content, "match"));
+        // Regression: used to panic with "byte index is not a char boundary".
+        let _ = snippet_for(content, "world");
+    }
+
+    #[test]
+    fn snippet_for_falls_back_to_prefix_when_no_match() {
+        let snippet = snippet_for("no tokens in query match here", "zzz");
+        assert!(snippet.starts_with("no tokens"));
+    }
+}
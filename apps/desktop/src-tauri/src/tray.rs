@@ -0,0 +1,93 @@
+// System tray icon, menu, and close-to-tray background mode.
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use tauri::menu::{Menu, MenuItem};
+use tauri::tray::TrayIconBuilder;
+use tauri::{AppHandle, Manager};
+
+use crate::hotkey::toggle_capture_window;
+
+const MAIN_WINDOW_LABEL: &str = "main";
+
+/// Whether the app should hide to the tray on window close instead of exiting.
+pub struct RunInBackground(pub AtomicBool);
+
+impl Default for RunInBackground {
+    fn default() -> Self {
+        Self(AtomicBool::new(true))
+    }
+}
+
+/// Builds the tray icon and menu. Called once from `.setup()`.
+pub fn init(app: &AppHandle) -> Result<(), String> {
+    let open = MenuItem::with_id(app, "open", "Open", true, None::<&str>).map_err(|e| e.to_string())?;
+    let new_note = MenuItem::with_id(app, "new_note", "New Note", true, None::<&str>)
+        .map_err(|e| e.to_string())?;
+    let quit = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>).map_err(|e| e.to_string())?;
+    let menu = Menu::with_items(app, &[&open, &new_note, &quit]).map_err(|e| e.to_string())?;
+
+    let icon = app
+        .default_window_icon()
+        .ok_or_else(|| "no default window icon configured for tray".to_string())?
+        .clone();
+
+    TrayIconBuilder::new()
+        .icon(icon)
+        .menu(&menu)
+        .on_menu_event(|app, event| match event.id.as_ref() {
+            "open" => {
+                if let Some(window) = app.get_webview_window(MAIN_WINDOW_LABEL) {
+                    let _ = window.show();
+                    let _ = window.set_focus();
+                }
+            }
+            "new_note" => toggle_capture_window(app),
+            "quit" => app.exit(0),
+            _ => {}
+        })
+        .build(app)
+        .map_err(|e| e.to_string())?;
+
+    app.manage(RunInBackground::default());
+    Ok(())
+}
+
+/// Intercepts a window's close request (any window, not only the main one):
+/// hides it instead of closing when background mode is on, mirroring the
+/// close-to-tray pattern of capture tools.
+pub fn handle_close_requested(app: &AppHandle, window: &tauri::Window, api: &tauri::CloseRequested) {
+    let background = app
+        .try_state::<RunInBackground>()
+        .map(|s| s.0.load(Ordering::Relaxed))
+        .unwrap_or(false);
+    if background {
+        api.prevent_close();
+        let _ = window.hide();
+    }
+}
+
+#[tauri::command]
+pub fn set_run_in_background(state: tauri::State<RunInBackground>, enabled: bool) {
+    state.0.store(enabled, Ordering::Relaxed);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_in_background_defaults_to_enabled() {
+        let state = RunInBackground::default();
+        assert!(state.0.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn run_in_background_toggle_round_trips() {
+        let state = RunInBackground::default();
+        state.0.store(false, Ordering::Relaxed);
+        assert!(!state.0.load(Ordering::Relaxed));
+
+        state.0.store(true, Ordering::Relaxed);
+        assert!(state.0.load(Ordering::Relaxed));
+    }
+}
@@ -0,0 +1,70 @@
+// Self-update support via tauri-plugin-updater, with download progress events.
+use std::sync::Mutex;
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_process::RestartExt;
+use tauri_plugin_updater::{Update, UpdaterExt};
+
+const UPDATE_PROGRESS_EVENT: &str = "updater://progress";
+const UPDATE_FINISHED_EVENT: &str = "updater://finished";
+
+/// Holds the update found by the last `check_for_update`, so `install_update`
+/// doesn't have to hit the update server again.
+#[derive(Default)]
+pub struct PendingUpdate(Mutex<Option<Update>>);
+
+#[derive(Debug, Serialize, Clone)]
+pub struct UpdateInfo {
+    pub version: String,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct UpdateProgress {
+    downloaded: usize,
+    total: Option<u64>,
+}
+
+#[tauri::command]
+pub async fn check_for_update(
+    app: AppHandle,
+    pending: tauri::State<'_, PendingUpdate>,
+) -> Result<Option<UpdateInfo>, String> {
+    let update = app.updater().map_err(|e| e.to_string())?.check().await.map_err(|e| e.to_string())?;
+    let info = update.as_ref().map(|u| UpdateInfo {
+        version: u.version.clone(),
+        notes: u.body.clone(),
+    });
+    *pending.0.lock().map_err(|e| e.to_string())? = update;
+    Ok(info)
+}
+
+#[tauri::command]
+pub async fn install_update(
+    app: AppHandle,
+    pending: tauri::State<'_, PendingUpdate>,
+) -> Result<(), String> {
+    let update = pending
+        .0
+        .lock()
+        .map_err(|e| e.to_string())?
+        .take()
+        .ok_or_else(|| "no update available; call check_for_update first".to_string())?;
+
+    let mut downloaded = 0usize;
+    update
+        .download_and_install(
+            |chunk_len, total| {
+                downloaded += chunk_len;
+                let _ = app.emit(UPDATE_PROGRESS_EVENT, UpdateProgress { downloaded, total });
+            },
+            || {
+                let _ = app.emit(UPDATE_FINISHED_EVENT, ());
+            },
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+
+    app.restart();
+}